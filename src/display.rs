@@ -0,0 +1,91 @@
+use crate::MaybeList;
+use alloc::string::String;
+use core::fmt::{Display, Formatter, Result};
+
+impl<T> MaybeList<T> {
+    /// Returns an adaptor that displays the elements separated by `sep`
+    pub fn display_with<'a>(&'a self, sep: &'a str) -> DisplayWith<'a, T> {
+        DisplayWith { list: self, sep }
+    }
+
+    /// Returns an adaptor that displays the elements as a natural-language list
+    ///
+    /// For example, with a conjunction of `"and"` this displays `a, b and c`
+    pub fn display_natural<'a>(&'a self, conjunction: &'a str) -> DisplayNatural<'a, T> {
+        DisplayNatural {
+            list: self,
+            conjunction,
+        }
+    }
+
+    /// Concatenates the elements into a single string, separated by `sep`
+    pub fn join(&self, sep: &str) -> String
+    where
+        T: AsRef<str>,
+    {
+        match self {
+            MaybeList::Empty => String::new(),
+            MaybeList::One(item) => String::from(item.as_ref()),
+            MaybeList::Many(list) => {
+                let mut out = String::new();
+                for (i, item) in list.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(sep);
+                    }
+                    out.push_str(item.as_ref());
+                }
+                out
+            }
+        }
+    }
+}
+
+/// Displays a single element as-is, and many elements separated by `, `
+impl<T: Display> Display for MaybeList<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.display_with(", ").fmt(f)
+    }
+}
+
+/// Displays the elements of a MaybeList separated by a separator
+///
+/// This is created by [`MaybeList::display_with`]
+pub struct DisplayWith<'a, T> {
+    list: &'a MaybeList<T>,
+    sep: &'a str,
+}
+
+impl<'a, T: Display> Display for DisplayWith<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for (i, item) in self.list.iter().enumerate() {
+            if i > 0 {
+                f.write_str(self.sep)?;
+            }
+            item.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Displays the elements of a MaybeList as a natural-language list
+///
+/// This is created by [`MaybeList::display_natural`]
+pub struct DisplayNatural<'a, T> {
+    list: &'a MaybeList<T>,
+    conjunction: &'a str,
+}
+
+impl<'a, T: Display> Display for DisplayNatural<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let last = self.list.len().saturating_sub(1);
+        for (i, item) in self.list.iter().enumerate() {
+            if i == last && i > 0 {
+                write!(f, " {} ", self.conjunction)?;
+            } else if i > 0 {
+                f.write_str(", ")?;
+            }
+            item.fmt(f)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,173 @@
+use crate::{Iter, MaybeList, MaybeListIter};
+
+/// An error type that collects either one, or many errors
+///
+/// A single error is displayed as-is (and its `source()` is forwarded), many errors are displayed as a numbered list
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Errors<E> {
+    list: MaybeList<E>,
+}
+
+impl<E> Errors<E> {
+    /// An error list of one error
+    pub fn one(error: E) -> Self {
+        Self {
+            list: MaybeList::One(error),
+        }
+    }
+
+    /// Returns the number of errors
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns whether there are no errors, which is always `false`
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns an iterator over references to the errors
+    pub fn iter(&self) -> Iter<'_, E> {
+        self.list.iter()
+    }
+
+    /// Views the errors as a MaybeList
+    pub fn as_list(&self) -> &MaybeList<E> {
+        &self.list
+    }
+
+    /// Returns the underlying MaybeList of errors
+    pub fn into_inner(self) -> MaybeList<E> {
+        self.list
+    }
+
+    /// Adds an error to this list
+    pub fn push(&mut self, error: E) {
+        self.list.push(error)
+    }
+
+    /// Moves all of the errors from `other` onto the end of this list
+    pub fn merge(&mut self, other: Self) {
+        self.list.extend(other.list)
+    }
+
+    /// Combines two lists of errors into one
+    pub fn merged(mut self, other: Self) -> Self {
+        self.merge(other);
+        self
+    }
+}
+
+impl<E> From<E> for Errors<E> {
+    fn from(error: E) -> Self {
+        Self::one(error)
+    }
+}
+
+impl<E> core::convert::TryFrom<MaybeList<E>> for Errors<E> {
+    type Error = TryFromMaybeListError<E>;
+
+    /// Converts a list of at least one error, handing the list back if it is empty
+    fn try_from(mut list: MaybeList<E>) -> Result<Self, Self::Error> {
+        list.normalize();
+        match list {
+            MaybeList::Empty => Err(TryFromMaybeListError::new(list, Expected::AtLeastOne)),
+            list => Ok(Self { list }),
+        }
+    }
+}
+
+impl<E> IntoIterator for Errors<E> {
+    type Item = E;
+    type IntoIter = MaybeListIter<E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.into_iter()
+    }
+}
+
+impl<'a, E> IntoIterator for &'a Errors<E> {
+    type Item = &'a E;
+    type IntoIter = Iter<'a, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<E: core::fmt::Display> core::fmt::Display for Errors<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match &self.list {
+            MaybeList::One(error) => error.fmt(f),
+            list => {
+                for (i, error) in list.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str("\n")?;
+                    }
+                    write!(f, "{}. {}", i + 1, error)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for Errors<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.list {
+            MaybeList::One(error) => error.source(),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Expected {
+    Exactly(usize),
+    AtMostOne,
+    AtLeastOne,
+}
+
+/// The error returned when converting a [`MaybeList`] that does not have the expected number of elements
+///
+/// The original list can be recovered with [`TryFromMaybeListError::into_inner`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TryFromMaybeListError<T> {
+    list: MaybeList<T>,
+    expected: Expected,
+}
+
+impl<T> TryFromMaybeListError<T> {
+    pub(crate) fn new(list: MaybeList<T>, expected: Expected) -> Self {
+        Self { list, expected }
+    }
+
+    /// Returns a reference to the list that failed to convert
+    pub fn as_list(&self) -> &MaybeList<T> {
+        &self.list
+    }
+
+    /// Returns the list that failed to convert
+    pub fn into_inner(self) -> MaybeList<T> {
+        self.list
+    }
+}
+
+impl<T> core::fmt::Display for TryFromMaybeListError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.expected {
+            Expected::Exactly(1) => f.write_str("expected exactly one element")?,
+            Expected::Exactly(n) => write!(f, "expected exactly {} elements", n)?,
+            Expected::AtMostOne => f.write_str("expected at most one element")?,
+            Expected::AtLeastOne => f.write_str("expected at least one element")?,
+        }
+        match self.list.len() {
+            1 => f.write_str(", found 1 element"),
+            n => write!(f, ", found {} elements", n),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: core::fmt::Debug> std::error::Error for TryFromMaybeListError<T> {}
@@ -0,0 +1,89 @@
+use crate::MaybeList;
+use alloc::vec::Vec;
+
+/// Extension methods for collecting iterators into a [`MaybeList`]
+pub trait IteratorExt: Iterator + Sized {
+    /// Collects this iterator into a MaybeList
+    ///
+    /// This produces an `Empty` for no elements and a `One` for exactly one element
+    fn collect_maybe(self) -> MaybeList<Self::Item> {
+        self.collect()
+    }
+
+    /// Collects an iterator of `Result`s or `Option`s into a MaybeList, stopping at the first failure
+    fn try_collect_maybe(self) -> <Self::Item as TryItem>::Collected
+    where
+        Self::Item: TryItem,
+    {
+        <Self::Item as TryItem>::try_collect(self)
+    }
+
+    /// Collects an iterator of `Result`s, returning either all of the successes or all of the failures
+    ///
+    /// See [`MaybeList::partition_results`]
+    fn collect_all<T, E>(self) -> Result<MaybeList<T>, MaybeList<E>>
+    where
+        Self: Iterator<Item = Result<T, E>>,
+    {
+        MaybeList::partition_results(self)
+    }
+
+    /// Returns the only element of this iterator
+    ///
+    /// If the iterator yields no elements or more than one, the elements are returned as the error
+    fn exactly_one(mut self) -> Result<Self::Item, MaybeList<Self::Item>> {
+        let first = match self.next() {
+            Some(first) => first,
+            None => return Err(MaybeList::Empty),
+        };
+        match self.next() {
+            None => Ok(first),
+            Some(second) => {
+                let (lower, _) = self.size_hint();
+                let mut list = Vec::with_capacity(lower.saturating_add(2));
+                list.push(first);
+                list.push(second);
+                list.extend(self);
+                Err(MaybeList::Many(list))
+            }
+        }
+    }
+
+    /// Returns the only element of this iterator, or `None` if it is empty
+    ///
+    /// If the iterator yields more than one element, the elements are returned as the error
+    fn at_most_one(self) -> Result<Option<Self::Item>, MaybeList<Self::Item>> {
+        match self.exactly_one() {
+            Ok(item) => Ok(Some(item)),
+            Err(MaybeList::Empty) => Ok(None),
+            Err(list) => Err(list),
+        }
+    }
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+/// An item that can be collected by [`IteratorExt::try_collect_maybe`]
+pub trait TryItem: Sized {
+    /// The type produced by collecting an iterator of this item
+    type Collected;
+
+    /// Collects the iterator, stopping at the first failure
+    fn try_collect<I: Iterator<Item = Self>>(iter: I) -> Self::Collected;
+}
+
+impl<T, E> TryItem for Result<T, E> {
+    type Collected = Result<MaybeList<T>, E>;
+
+    fn try_collect<I: Iterator<Item = Self>>(iter: I) -> Self::Collected {
+        iter.collect()
+    }
+}
+
+impl<T> TryItem for Option<T> {
+    type Collected = Option<MaybeList<T>>;
+
+    fn try_collect<I: Iterator<Item = Self>>(iter: I) -> Self::Collected {
+        iter.collect()
+    }
+}
@@ -0,0 +1,598 @@
+use crate::{Iter, IterMut, MaybeList};
+use alloc::vec::Vec;
+use core::mem::{ManuallyDrop, MaybeUninit};
+
+/// A List type that stores up to `N` elements inline, spilling to the heap beyond that
+///
+/// With the default `N = 1` this behaves like a [`MaybeList`], and converts to and from one with `From`
+pub struct InlineMaybeList<T, const N: usize = 1> {
+    repr: Repr<T, N>,
+}
+
+enum Repr<T, const N: usize> {
+    Inline(InlineBuf<T, N>),
+    Spilled(Vec<T>),
+}
+
+impl<T, const N: usize> InlineMaybeList<T, N> {
+    /// An empty InlineMaybeList
+    pub fn new() -> Self {
+        Self {
+            repr: Repr::Inline(InlineBuf::new()),
+        }
+    }
+
+    /// An InlineMaybeList of one element
+    pub fn one(item: T) -> Self {
+        let mut list = Self::new();
+        list.push(item);
+        list
+    }
+
+    /// An InlineMaybeList of many elements
+    ///
+    /// This only allocates if the iterator yields more than `N` elements
+    pub fn many(list: impl IntoIterator<Item = T>) -> Self {
+        list.into_iter().collect()
+    }
+
+    /// Returns the length of this list
+    pub fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    /// Returns whether this list is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns whether the elements are stored inline
+    pub fn is_inline(&self) -> bool {
+        matches!(self.repr, Repr::Inline(..))
+    }
+
+    /// Appends an element to the back of this list
+    ///
+    /// The list spills to the heap when it grows beyond `N` elements
+    pub fn push(&mut self, item: T) {
+        match &mut self.repr {
+            Repr::Inline(buf) => {
+                if let Err(item) = buf.push(item) {
+                    let mut list = Vec::with_capacity(core::cmp::max(N * 2, N + 1));
+                    buf.drain_into(&mut list);
+                    list.push(item);
+                    self.repr = Repr::Spilled(list);
+                }
+            }
+            Repr::Spilled(list) => list.push(item),
+        }
+    }
+
+    /// Views this list as a slice
+    pub fn as_slice(&self) -> &[T] {
+        match &self.repr {
+            Repr::Inline(buf) => buf.as_slice(),
+            Repr::Spilled(list) => list.as_slice(),
+        }
+    }
+
+    /// Views this list as a mutable slice
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        match &mut self.repr {
+            Repr::Inline(buf) => buf.as_mut_slice(),
+            Repr::Spilled(list) => list.as_mut_slice(),
+        }
+    }
+
+    /// Returns an iterator over references to the elements
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            inner: self.as_slice().iter(),
+        }
+    }
+
+    /// Returns an iterator over mutable references to the elements
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            inner: self.as_mut_slice().iter_mut(),
+        }
+    }
+}
+
+impl<T, const N: usize> Default for InlineMaybeList<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for InlineMaybeList<T, N> {
+    fn clone(&self) -> Self {
+        let repr = match &self.repr {
+            Repr::Inline(buf) => Repr::Inline(buf.clone()),
+            Repr::Spilled(list) => Repr::Spilled(list.clone()),
+        };
+        Self { repr }
+    }
+}
+
+impl<T: core::fmt::Debug, const N: usize> core::fmt::Debug for InlineMaybeList<T, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut s = f.debug_struct("InlineMaybeList");
+        match &self.repr {
+            Repr::Inline(buf) => s.field("inline", &buf.as_slice()),
+            Repr::Spilled(list) => s.field("spilled", &list),
+        }
+        .finish()
+    }
+}
+
+impl<T, U, const N: usize, const M: usize> PartialEq<InlineMaybeList<U, M>>
+    for InlineMaybeList<T, N>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &InlineMaybeList<U, M>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for InlineMaybeList<T, N> {}
+
+impl<T: core::hash::Hash, const N: usize> core::hash::Hash for InlineMaybeList<T, N> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<T, const N: usize> core::ops::Deref for InlineMaybeList<T, N> {
+    type Target = [T];
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> core::ops::DerefMut for InlineMaybeList<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for InlineMaybeList<T, N> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for InlineMaybeList<T, N> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const N: usize> From<T> for InlineMaybeList<T, N> {
+    fn from(d: T) -> Self {
+        Self::one(d)
+    }
+}
+
+impl<T, const N: usize> From<MaybeList<T>> for InlineMaybeList<T, N> {
+    fn from(d: MaybeList<T>) -> Self {
+        match d {
+            MaybeList::Many(list) if list.len() > N => Self {
+                repr: Repr::Spilled(list),
+            },
+            list => list.into_iter().collect(),
+        }
+    }
+}
+
+impl<T, const N: usize> From<InlineMaybeList<T, N>> for MaybeList<T> {
+    fn from(d: InlineMaybeList<T, N>) -> Self {
+        match d.repr {
+            Repr::Inline(buf) => buf.into_items().collect(),
+            Repr::Spilled(list) => {
+                let mut list = MaybeList::Many(list);
+                list.normalize();
+                list
+            }
+        }
+    }
+}
+
+impl<T, const N: usize> core::iter::FromIterator<T> for InlineMaybeList<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        for item in iter {
+            list.push(item)
+        }
+        list
+    }
+}
+
+impl<T, const N: usize> IntoIterator for InlineMaybeList<T, N> {
+    type Item = T;
+    type IntoIter = InlineMaybeListIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let inner = match self.repr {
+            Repr::Inline(buf) => InlineIntoIterRepr::Inline(buf.into_items()),
+            Repr::Spilled(list) => InlineIntoIterRepr::Spilled(list.into_iter()),
+        };
+        Self::IntoIter { inner }
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a InlineMaybeList<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut InlineMaybeList<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+enum InlineIntoIterRepr<T, const N: usize> {
+    Inline(InlineBufIter<T, N>),
+    Spilled(alloc::vec::IntoIter<T>),
+}
+
+/// An iterator over an InlineMaybeList
+pub struct InlineMaybeListIter<T, const N: usize> {
+    inner: InlineIntoIterRepr<T, N>,
+}
+
+impl<T, const N: usize> InlineMaybeListIter<T, N> {
+    /// Returns the remaining items of this iterator as a slice
+    pub fn as_slice(&self) -> &[T] {
+        match &self.inner {
+            InlineIntoIterRepr::Inline(iter) => iter.as_slice(),
+            InlineIntoIterRepr::Spilled(iter) => iter.as_slice(),
+        }
+    }
+}
+
+impl<T, const N: usize> Iterator for InlineMaybeListIter<T, N> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.inner {
+            InlineIntoIterRepr::Inline(ref mut iter) => iter.next(),
+            InlineIntoIterRepr::Spilled(ref mut iter) => iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.as_slice().len();
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> core::iter::DoubleEndedIterator for InlineMaybeListIter<T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.inner {
+            InlineIntoIterRepr::Inline(ref mut iter) => iter.next_back(),
+            InlineIntoIterRepr::Spilled(ref mut iter) => iter.next_back(),
+        }
+    }
+}
+
+impl<T, const N: usize> core::iter::FusedIterator for InlineMaybeListIter<T, N> {}
+
+impl<T, const N: usize> core::iter::ExactSizeIterator for InlineMaybeListIter<T, N> {}
+
+// inline storage for up to `N` elements, of which the first `len` are initialized
+struct InlineBuf<T, const N: usize> {
+    buf: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> InlineBuf<T, N> {
+    fn new() -> Self {
+        Self {
+            buf: core::array::from_fn(|_| MaybeUninit::uninit()),
+            len: 0,
+        }
+    }
+
+    fn as_slice(&self) -> &[T] {
+        // SAFETY: the first `len` items are initialized
+        unsafe { core::slice::from_raw_parts(self.buf.as_ptr().cast(), self.len) }
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: the first `len` items are initialized
+        unsafe { core::slice::from_raw_parts_mut(self.buf.as_mut_ptr().cast(), self.len) }
+    }
+
+    // hands the item back if the buffer is full
+    fn push(&mut self, item: T) -> Result<(), T> {
+        match self.buf.get_mut(self.len) {
+            Some(slot) => {
+                slot.write(item);
+                self.len += 1;
+                Ok(())
+            }
+            None => Err(item),
+        }
+    }
+
+    // moves every item into `list`, leaving this buffer empty
+    fn drain_into(&mut self, list: &mut Vec<T>) {
+        let len = core::mem::replace(&mut self.len, 0);
+        for slot in &self.buf[..len] {
+            // SAFETY: the item was initialized, and `len` was reset so it is read only once
+            list.push(unsafe { slot.assume_init_read() })
+        }
+    }
+
+    fn into_items(self) -> InlineBufIter<T, N> {
+        let this = ManuallyDrop::new(self);
+        InlineBufIter {
+            // SAFETY: `this` is never dropped, so the items are only owned by the iterator
+            buf: unsafe { core::ptr::read(&this.buf) },
+            start: 0,
+            end: this.len,
+        }
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for InlineBuf<T, N> {
+    fn clone(&self) -> Self {
+        let mut buf = Self::new();
+        for item in self.as_slice() {
+            // this cannot fail, `self` holds at most `N` items
+            let _ = buf.push(item.clone());
+        }
+        buf
+    }
+}
+
+impl<T, const N: usize> Drop for InlineBuf<T, N> {
+    fn drop(&mut self) {
+        // SAFETY: the initialized items are dropped exactly once
+        unsafe { core::ptr::drop_in_place(self.as_mut_slice()) }
+    }
+}
+
+// an owning iterator over an `InlineBuf`, the items in `start..end` are initialized
+struct InlineBufIter<T, const N: usize> {
+    buf: [MaybeUninit<T>; N],
+    start: usize,
+    end: usize,
+}
+
+impl<T, const N: usize> InlineBufIter<T, N> {
+    fn as_slice(&self) -> &[T] {
+        let items = &self.buf[self.start..self.end];
+        // SAFETY: the items in `start..end` are initialized
+        unsafe { core::slice::from_raw_parts(items.as_ptr().cast(), items.len()) }
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        let items = &mut self.buf[self.start..self.end];
+        // SAFETY: the items in `start..end` are initialized
+        unsafe { core::slice::from_raw_parts_mut(items.as_mut_ptr().cast(), items.len()) }
+    }
+}
+
+impl<T, const N: usize> Iterator for InlineBufIter<T, N> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        self.start += 1;
+        // SAFETY: the item was initialized, and is now outside of `start..end`
+        Some(unsafe { self.buf[self.start - 1].assume_init_read() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for InlineBufIter<T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        self.end -= 1;
+        // SAFETY: the item was initialized, and is now outside of `start..end`
+        Some(unsafe { self.buf[self.end].assume_init_read() })
+    }
+}
+
+impl<T, const N: usize> Drop for InlineBufIter<T, N> {
+    fn drop(&mut self) {
+        // SAFETY: the remaining items are dropped exactly once
+        unsafe { core::ptr::drop_in_place(self.as_mut_slice()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{rc::Rc, vec};
+    use core::cell::Cell;
+
+    // counts how many times it has been dropped
+    #[derive(Clone, Debug)]
+    struct Counted {
+        value: usize,
+        drops: Rc<Cell<usize>>,
+    }
+
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1)
+        }
+    }
+
+    impl PartialEq<usize> for Counted {
+        fn eq(&self, other: &usize) -> bool {
+            self.value == *other
+        }
+    }
+
+    fn counted<const N: usize>(len: usize) -> (InlineMaybeList<Counted, N>, Rc<Cell<usize>>) {
+        let drops = Rc::new(Cell::new(0));
+        let list = (0..len)
+            .map(|value| Counted {
+                value,
+                drops: drops.clone(),
+            })
+            .collect();
+        (list, drops)
+    }
+
+    #[test]
+    fn drop_inline() {
+        let (list, drops) = counted::<4>(3);
+        assert!(list.is_inline());
+        assert_eq!(list.as_slice(), [0, 1, 2]);
+        drop(list);
+        assert_eq!(drops.get(), 3);
+    }
+
+    #[test]
+    fn partial_iteration_then_drop() {
+        let (list, drops) = counted::<4>(4);
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next().unwrap(), 0);
+        assert_eq!(drops.get(), 1);
+        assert_eq!(iter.next_back().unwrap(), 3);
+        assert_eq!(drops.get(), 2);
+        assert_eq!(iter.as_slice(), [1, 2]);
+        assert_eq!(iter.len(), 2);
+        drop(iter);
+        assert_eq!(drops.get(), 4);
+
+        let (list, drops) = counted::<4>(3);
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next_back().unwrap(), 2);
+        assert_eq!(iter.next_back().unwrap(), 1);
+        assert_eq!(iter.next().unwrap(), 0);
+        assert!(iter.next().is_none());
+        assert!(iter.next_back().is_none());
+        drop(iter);
+        assert_eq!(drops.get(), 3);
+    }
+
+    #[test]
+    fn partial_iteration_of_spilled_then_drop() {
+        let (list, drops) = counted::<2>(5);
+        assert!(!list.is_inline());
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next().unwrap(), 0);
+        assert_eq!(iter.next_back().unwrap(), 4);
+        drop(iter);
+        assert_eq!(drops.get(), 5);
+    }
+
+    #[test]
+    fn spill_at_capacity() {
+        let (mut list, drops) = counted::<3>(3);
+        assert!(list.is_inline());
+        assert_eq!(list.len(), 3);
+
+        list.push(Counted {
+            value: 3,
+            drops: drops.clone(),
+        });
+        assert!(!list.is_inline());
+        assert_eq!(list.as_slice(), [0, 1, 2, 3]);
+        assert_eq!(drops.get(), 0);
+        match &list.repr {
+            Repr::Spilled(list) => assert!(list.capacity() >= 6),
+            Repr::Inline(..) => unreachable!(),
+        }
+
+        drop(list);
+        assert_eq!(drops.get(), 4);
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let mut list = InlineMaybeList::<_, 0>::new();
+        assert!(list.is_inline());
+        assert!(list.is_empty());
+        assert!(list.clone().into_iter().next().is_none());
+
+        let (other, drops) = counted::<0>(2);
+        assert!(!other.is_inline());
+        for item in other {
+            list.push(item);
+        }
+        assert_eq!(list.as_slice(), [0, 1]);
+        assert_eq!(MaybeList::<Counted>::from(list.clone()), [0, 1]);
+        drop(list);
+        assert_eq!(drops.get(), 4);
+    }
+
+    #[test]
+    fn clone_inline_and_spilled() {
+        let (list, drops) = counted::<2>(2);
+        let cloned = list.clone();
+        assert!(cloned.is_inline());
+        assert_eq!(cloned.as_slice(), [0, 1]);
+        drop(list);
+        assert_eq!(drops.get(), 2);
+        drop(cloned);
+        assert_eq!(drops.get(), 4);
+
+        let (list, drops) = counted::<2>(3);
+        let cloned = list.clone();
+        assert!(!cloned.is_inline());
+        assert_eq!(cloned.as_slice(), [0, 1, 2]);
+        drop((list, cloned));
+        assert_eq!(drops.get(), 6);
+    }
+
+    #[test]
+    fn from_maybe_list() {
+        let list: InlineMaybeList<i32> = MaybeList::Empty.into();
+        assert!(list.is_inline() && list.is_empty());
+
+        let list: InlineMaybeList<i32> = MaybeList::one(1).into();
+        assert!(list.is_inline());
+        assert_eq!(list.as_slice(), [1]);
+
+        let list: InlineMaybeList<i32, 2> = MaybeList::many(vec![1, 2]).into();
+        assert!(list.is_inline());
+        assert_eq!(list.as_slice(), [1, 2]);
+
+        let list: InlineMaybeList<i32> = MaybeList::many(vec![1, 2]).into();
+        assert!(!list.is_inline());
+        assert_eq!(list.as_slice(), [1, 2]);
+    }
+
+    #[test]
+    fn into_maybe_list() {
+        let list = MaybeList::<i32>::from(InlineMaybeList::<_, 2>::new());
+        assert!(matches!(list, MaybeList::Empty));
+
+        let list = MaybeList::<i32>::from(InlineMaybeList::<_, 2>::one(1));
+        assert!(matches!(list, MaybeList::One(1)));
+
+        let list = MaybeList::<i32>::from(InlineMaybeList::<_, 2>::many([1, 2]));
+        assert!(matches!(list, MaybeList::Many(ref list) if list == &[1, 2]));
+
+        let list = MaybeList::<i32>::from(InlineMaybeList::<_, 1>::many([1, 2, 3]));
+        assert!(matches!(list, MaybeList::Many(ref list) if list == &[1, 2, 3]));
+
+        let (list, drops) = counted::<3>(2);
+        let list = MaybeList::<Counted>::from(list);
+        assert_eq!(drops.get(), 0);
+        drop(list);
+        assert_eq!(drops.get(), 2);
+    }
+}
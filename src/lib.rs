@@ -37,9 +37,72 @@ for (input, len, expected) in inputs {
 
 ```
 */
+#![no_std]
 
-/// A List type that holds either 1 element, or many elements
+extern crate alloc;
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+use alloc::vec::Vec;
+
+mod inline;
+pub use self::inline::{InlineMaybeList, InlineMaybeListIter};
+
+mod error;
+use self::error::Expected;
+pub use self::error::{Errors, TryFromMaybeListError};
+
+mod ext;
+pub use self::ext::{IteratorExt, TryItem};
+
+mod non_empty;
+pub use self::non_empty::NonEmptyMaybeList;
+
+mod list_ref;
+pub use self::list_ref::MaybeListRef;
+
+mod display;
+pub use self::display::{DisplayNatural, DisplayWith};
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+#[cfg(feature = "rayon")]
+mod rayon;
+
+/// Creates a [`MaybeList`] containing the arguments, like `vec!`
+///
+/// ```rust
+/// use maybe_list::{maybe_list, MaybeList};
+///
+/// let empty: MaybeList<i32> = maybe_list![];
+/// assert!(matches!(empty, MaybeList::Empty));
+/// assert!(matches!(maybe_list![1], MaybeList::One(1)));
+/// assert_eq!(maybe_list![1, 2, 3], [1, 2, 3]);
+/// assert_eq!(maybe_list![0; 3], [0, 0, 0]);
+/// ```
+#[macro_export]
+macro_rules! maybe_list {
+    () => {
+        $crate::MaybeList::Empty
+    };
+    ($elem:expr; $n:expr) => {
+        $crate::MaybeList::from_elem($elem, $n)
+    };
+    ($item:expr $(,)?) => {
+        $crate::MaybeList::One($item)
+    };
+    ($($item:expr),+ $(,)?) => {
+        $crate::MaybeList::many([$($item),+])
+    };
+}
+
+/// A List type that holds either no elements, 1 element, or many elements
+#[derive(Clone, Default)]
 pub enum MaybeList<T> {
+    /// No elements
+    #[default]
+    Empty,
     /// A single element
     One(T),
     /// Multiple elements (heap allocated)
@@ -47,18 +110,84 @@ pub enum MaybeList<T> {
 }
 
 impl<T> MaybeList<T> {
+    /// A MaybeList of no elements
+    pub fn empty() -> Self {
+        MaybeList::Empty
+    }
+    /// A MaybeList with room for at least `capacity` elements
+    ///
+    /// This only allocates if `capacity` is greater than 1
+    pub fn with_capacity(capacity: usize) -> Self {
+        match capacity {
+            0 | 1 => MaybeList::Empty,
+            _ => MaybeList::Many(Vec::with_capacity(capacity)),
+        }
+    }
+    /// A MaybeList of `n` clones of `elem`
+    pub fn from_elem(elem: T, n: usize) -> Self
+    where
+        T: Clone,
+    {
+        match n {
+            0 => MaybeList::Empty,
+            1 => MaybeList::One(elem),
+            n => MaybeList::Many(alloc::vec![elem; n]),
+        }
+    }
     /// A MaybeList of one element
     pub fn one(item: T) -> Self {
         MaybeList::One(item)
     }
     /// A MaybeList of many elements
+    ///
+    /// If the iterator yields no elements this produces an `Empty`,
+    /// and if it yields exactly one element this produces a `One`
     pub fn many(list: impl IntoIterator<Item = T>) -> Self {
-        MaybeList::Many(list.into_iter().collect())
+        list.into_iter().collect()
+    }
+
+    /// Collects every result of an iterator, returning either all of the successes or all of the failures
+    ///
+    /// Unlike collecting into a `Result`, this does not stop at the first error
+    pub fn partition_results<E, I>(iter: I) -> Result<Self, MaybeList<E>>
+    where
+        I: IntoIterator<Item = Result<T, E>>,
+    {
+        let mut oks = MaybeList::Empty;
+        let mut errors = MaybeList::Empty;
+        for item in iter {
+            match item {
+                Ok(item) if errors.is_empty() => oks.push(item),
+                Ok(..) => {}
+                Err(error) => {
+                    oks.clear();
+                    errors.push(error)
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(oks)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Collapses an empty `Many` into an `Empty`, and a `Many` of exactly one element into a `One`
+    pub fn normalize(&mut self) {
+        if let MaybeList::Many(list) = self {
+            match list.len() {
+                0 => *self = MaybeList::Empty,
+                1 => *self = MaybeList::One(list.pop().unwrap()),
+                _ => {}
+            }
+        }
     }
 
     /// Returns the length of this list
     pub fn len(&self) -> usize {
         match self {
+            MaybeList::Empty => 0,
             MaybeList::One(..) => 1,
             MaybeList::Many(list) => list.len(),
         }
@@ -68,18 +197,714 @@ impl<T> MaybeList<T> {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Returns the number of elements this list can hold without allocating
+    ///
+    /// An `Empty` or `One` list can always hold 1 element
+    pub fn capacity(&self) -> usize {
+        match self {
+            MaybeList::Empty | MaybeList::One(..) => 1,
+            MaybeList::Many(list) => list.capacity(),
+        }
+    }
+
+    /// Reserves capacity for at least `additional` more elements
+    ///
+    /// An `Empty` or `One` list is only promoted to `Many` if it cannot hold the elements without allocating
+    ///
+    /// ```rust
+    /// use maybe_list::MaybeList;
+    ///
+    /// let mut list = MaybeList::<i32>::Empty;
+    /// list.reserve(1);
+    /// assert!(matches!(list, MaybeList::Empty));
+    /// assert_eq!(list.capacity(), 1);
+    ///
+    /// list.reserve(2);
+    /// assert!(matches!(list, MaybeList::Many(_)));
+    /// assert!(list.capacity() >= 2);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        match self {
+            MaybeList::Many(list) => list.reserve(additional),
+            _ if additional <= 1 - self.len() => {}
+            _ => {
+                self.promote(additional);
+            }
+        }
+    }
+
+    /// Reserves capacity for exactly `additional` more elements
+    ///
+    /// An `Empty` or `One` list is only promoted to `Many` if it cannot hold the elements without allocating
+    pub fn reserve_exact(&mut self, additional: usize) {
+        match self {
+            MaybeList::Many(list) => list.reserve_exact(additional),
+            _ if additional <= 1 - self.len() => {}
+            _ => {
+                self.promote(additional);
+            }
+        }
+    }
+
+    /// Tries to reserve capacity for at least `additional` more elements
+    ///
+    /// An `Empty` or `One` list is only promoted to `Many` if it cannot hold the elements without allocating
+    ///
+    /// # Errors
+    /// Returns an error if the capacity overflows, or the allocator reports a failure.
+    /// The list is left unchanged in that case
+    ///
+    /// ```rust
+    /// use maybe_list::MaybeList;
+    ///
+    /// let mut list = MaybeList::<u8>::Empty;
+    /// list.try_reserve(1).unwrap();
+    /// assert!(matches!(list, MaybeList::Empty));
+    ///
+    /// let mut list = MaybeList::one(1_u8);
+    /// assert!(list.try_reserve(usize::MAX).is_err());
+    /// assert!(matches!(list, MaybeList::One(1)));
+    ///
+    /// list.try_reserve(1).unwrap();
+    /// assert!(matches!(list, MaybeList::Many(_)));
+    /// assert!(list.capacity() >= 2);
+    /// ```
+    pub fn try_reserve(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), alloc::collections::TryReserveError> {
+        match self {
+            MaybeList::Many(list) => list.try_reserve(additional),
+            _ if additional <= 1 - self.len() => Ok(()),
+            _ => {
+                let mut list = Vec::new();
+                list.try_reserve(self.len().saturating_add(additional))?;
+                list.extend(self.take());
+
+                // this only fails if `len + additional` overflowed above
+                let result = list.try_reserve(additional);
+                *self = MaybeList::Many(list);
+                if result.is_err() {
+                    self.normalize();
+                }
+                result
+            }
+        }
+    }
+
+    /// Tries to append an element to the back of this list
+    ///
+    /// # Errors
+    /// Returns an error if the list needed to grow and the allocation failed
+    pub fn try_push(&mut self, item: T) -> Result<(), alloc::collections::TryReserveError> {
+        self.try_reserve(1)?;
+        self.push(item);
+        Ok(())
+    }
+
+    /// Shrinks the capacity of this list as much as possible
+    ///
+    /// A `Many` list of fewer than two elements is collapsed into `Empty` or `One`
+    ///
+    /// ```rust
+    /// use maybe_list::MaybeList;
+    ///
+    /// let mut list = MaybeList::one(1);
+    /// list.reserve(1);
+    /// assert!(matches!(list, MaybeList::Many(_)));
+    /// list.shrink_to_fit();
+    /// assert!(matches!(list, MaybeList::One(1)));
+    ///
+    /// let mut list = MaybeList::with_capacity(10);
+    /// list.extend([1, 2]);
+    /// list.shrink_to_fit();
+    /// assert!(matches!(list, MaybeList::Many(_)));
+    /// assert_eq!(list.capacity(), 2);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.normalize();
+        if let MaybeList::Many(list) = self {
+            list.shrink_to_fit()
+        }
+    }
+
+    /// Returns a reference to the element if this list has exactly one
+    pub fn as_one(&self) -> Option<&T> {
+        match self.as_slice() {
+            [item] => Some(item),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the element if this list has exactly one
+    pub fn as_one_mut(&mut self) -> Option<&mut T> {
+        match self.as_mut_slice() {
+            [item] => Some(item),
+            _ => None,
+        }
+    }
+
+    /// Returns the element if this list has exactly one, otherwise the list is handed back
+    pub fn into_one(mut self) -> Result<T, Self> {
+        self.normalize();
+        match self {
+            MaybeList::One(item) => Ok(item),
+            list => Err(list),
+        }
+    }
+
+    /// Returns the element if this list has exactly one
+    ///
+    /// # Panics
+    /// Panics with `msg` if this list does not have exactly one element
+    #[track_caller]
+    pub fn expect_one(self, msg: &str) -> T {
+        match self.into_one() {
+            Ok(item) => item,
+            Err(list) => panic!(
+                "{}: {}",
+                msg,
+                TryFromMaybeListError::new(list, Expected::Exactly(1))
+            ),
+        }
+    }
+
+    /// Returns the first element, dropping the rest
+    pub fn into_first(self) -> Option<T> {
+        match self {
+            MaybeList::Empty => None,
+            MaybeList::One(item) => Some(item),
+            MaybeList::Many(list) => list.into_iter().next(),
+        }
+    }
+
+    /// Returns the last element, dropping the rest
+    pub fn into_last(self) -> Option<T> {
+        match self {
+            MaybeList::Empty => None,
+            MaybeList::One(item) => Some(item),
+            MaybeList::Many(mut list) => list.pop(),
+        }
+    }
+
+    /// Appends an element to the back of this list
+    ///
+    /// An `Empty` list becomes a `One`, and a `One` list is promoted to `Many` to make room for the new element
+    pub fn push(&mut self, item: T) {
+        match self {
+            MaybeList::Empty => *self = MaybeList::One(item),
+            _ => self.promote(1).push(item),
+        }
+    }
+
+    /// Inserts an element at `index`, shifting all elements after it to the right
+    ///
+    /// An `Empty` list becomes a `One`, and a `One` list is promoted to `Many` to make room for the new element
+    ///
+    /// # Panics
+    /// Panics if `index > len`
+    pub fn insert(&mut self, index: usize, item: T) {
+        match self {
+            MaybeList::Empty if index == 0 => *self = MaybeList::One(item),
+            MaybeList::Empty => {
+                panic!("insertion index (is {}) should be <= len (is 0)", index)
+            }
+            MaybeList::One(..) if index > 1 => {
+                panic!("insertion index (is {}) should be <= len (is 1)", index)
+            }
+            _ => self.promote(1).insert(index, item),
+        }
+    }
+
+    /// Removes the last element from this list and returns it, or `None` if it is empty
+    ///
+    /// If only one element remains, the list is collapsed back into `One`
+    pub fn pop(&mut self) -> Option<T> {
+        match self {
+            MaybeList::Empty => None,
+            MaybeList::One(..) => self.take().into_iter().next(),
+            MaybeList::Many(list) => {
+                let item = list.pop();
+                self.normalize();
+                item
+            }
+        }
+    }
+
+    /// Removes and returns the element at `index`, shifting all elements after it to the left
+    ///
+    /// If only one element remains, the list is collapsed back into `One`
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds
+    pub fn remove(&mut self, index: usize) -> T {
+        match self {
+            MaybeList::Empty => {
+                panic!("removal index (is {}) should be < len (is 0)", index)
+            }
+            MaybeList::One(..) if index == 0 => self.pop().unwrap(),
+            MaybeList::One(..) => {
+                panic!("removal index (is {}) should be < len (is 1)", index)
+            }
+            MaybeList::Many(list) => {
+                let item = list.remove(index);
+                self.normalize();
+                item
+            }
+        }
+    }
+
+    /// Removes and returns the element at `index`, replacing it with the last element
+    ///
+    /// This does not preserve ordering, but is O(1).
+    /// If only one element remains, the list is collapsed back into `One`
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        match self {
+            MaybeList::Empty => {
+                panic!("swap_remove index (is {}) should be < len (is 0)", index)
+            }
+            MaybeList::One(..) if index == 0 => self.pop().unwrap(),
+            MaybeList::One(..) => {
+                panic!("swap_remove index (is {}) should be < len (is 1)", index)
+            }
+            MaybeList::Many(list) => {
+                let item = list.swap_remove(index);
+                self.normalize();
+                item
+            }
+        }
+    }
+
+    /// Shortens this list, keeping the first `len` elements and dropping the rest
+    ///
+    /// If only one element remains, the list is collapsed back into `One`
+    pub fn truncate(&mut self, len: usize) {
+        match self {
+            MaybeList::One(..) if len == 0 => self.clear(),
+            MaybeList::Empty | MaybeList::One(..) => {}
+            MaybeList::Many(list) => {
+                list.truncate(len);
+                self.normalize();
+            }
+        }
+    }
+
+    /// Removes all elements from this list
+    ///
+    /// Like the other shrinking methods, the list is collapsed into `Empty`, so a `Many` list releases its allocation
+    pub fn clear(&mut self) {
+        *self = MaybeList::Empty;
+    }
+
+    /// Moves all of the elements of `other` into this list, leaving `other` empty
+    ///
+    /// If this list is empty, it takes over `other`'s allocation
+    pub fn append(&mut self, other: &mut Self) {
+        match other.take() {
+            MaybeList::Empty => {}
+            other if self.is_empty() => *self = other,
+            MaybeList::One(item) => self.push(item),
+            MaybeList::Many(mut list) => self.promote(list.len()).append(&mut list),
+        }
+    }
+
+    /// Splits this list into two at `at`, returning the elements from `at` onwards
+    ///
+    /// Both lists are collapsed into `Empty` or `One` where applicable
+    ///
+    /// # Panics
+    /// Panics if `at > len`
+    ///
+    /// ```rust
+    /// use maybe_list::MaybeList;
+    ///
+    /// let mut list = MaybeList::many(vec![1, 2, 3]);
+    /// let tail = list.split_off(1);
+    /// assert!(matches!(list, MaybeList::One(1)));
+    /// assert_eq!(tail, [2, 3]);
+    ///
+    /// let tail = list.split_off(0);
+    /// assert!(matches!(list, MaybeList::Empty));
+    /// assert!(matches!(tail, MaybeList::One(1)));
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Self {
+        match self {
+            MaybeList::Many(list) => {
+                let mut tail = MaybeList::Many(list.split_off(at));
+                tail.normalize();
+                self.normalize();
+                tail
+            }
+            _ if at == 0 => self.take(),
+            _ if at == self.len() => MaybeList::Empty,
+            _ => panic!(
+                "`at` split index (is {}) should be <= len (is {})",
+                at,
+                self.len()
+            ),
+        }
+    }
+
+    /// Removes the elements in `range` from this list, returning them as an iterator
+    ///
+    /// The remaining list is collapsed into `Empty` or `One` where applicable.
+    /// Dropping the iterator drops any elements in the range that were not yielded,
+    /// and leaking it (e.g. with `mem::forget`) may leave the list shorter than expected.
+    ///
+    /// # Panics
+    /// Panics if the range is out of bounds
+    ///
+    /// ```rust
+    /// use maybe_list::MaybeList;
+    ///
+    /// let mut list = MaybeList::many(vec![1, 2, 3, 4]);
+    /// assert!(list.drain(1..2).eq([2]));
+    /// assert_eq!(list, [1, 3, 4]);
+    ///
+    /// assert!(list.drain(..2).eq([1, 3]));
+    /// assert!(matches!(list, MaybeList::One(4)));
+    ///
+    /// assert!(list.drain(..).eq([4]));
+    /// assert!(matches!(list, MaybeList::Empty));
+    /// ```
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T>
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        let range = resolve_range(range, self.len());
+        let remaining = self.len() - range.len();
+        if let (MaybeList::Many(list), 0..=1) = (&mut *self, remaining) {
+            // at most one element is left behind, so move it into `self` and iterate the original vec
+            let mut list = core::mem::take(list).into_iter();
+            *self = match remaining {
+                0 => MaybeList::Empty,
+                _ if range.start == 0 => MaybeList::One(list.next_back().unwrap()),
+                _ => MaybeList::One(list.next().unwrap()),
+            };
+            let iter = MaybeListIter {
+                item: PartialMaybeList::Many(list),
+            };
+            return Drain {
+                inner: DrainRepr::Owned(iter),
+            };
+        }
+
+        let inner = match self {
+            MaybeList::Many(list) => DrainRepr::Vec(list.drain(range)),
+            _ if range.is_empty() => DrainRepr::Owned(MaybeList::Empty.into_iter()),
+            _ => DrainRepr::Owned(self.take().into_iter()),
+        };
+
+        Drain { inner }
+    }
+
+    /// Retains only the elements for which `f` returns `true`
+    ///
+    /// The list is collapsed into `Empty` or `One` where applicable
+    ///
+    /// ```rust
+    /// use maybe_list::MaybeList;
+    ///
+    /// let mut list = MaybeList::many(vec![1, 2, 3, 4]);
+    /// list.retain(|&x| x % 2 == 0);
+    /// assert_eq!(list, [2, 4]);
+    ///
+    /// list.retain(|&x| x > 2);
+    /// assert!(matches!(list, MaybeList::One(4)));
+    ///
+    /// list.retain(|&x| x > 4);
+    /// assert!(matches!(list, MaybeList::Empty));
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|item| f(item))
+    }
+
+    /// Retains only the elements for which `f` returns `true`, passing a mutable reference to each element
+    ///
+    /// The list is collapsed into `Empty` or `One` where applicable
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        match self {
+            MaybeList::Empty => {}
+            MaybeList::One(item) => {
+                if !f(item) {
+                    self.clear()
+                }
+            }
+            MaybeList::Many(list) => {
+                list.retain_mut(f);
+                self.normalize();
+            }
+        }
+    }
+
+    /// Removes consecutive repeated elements
+    ///
+    /// The list is collapsed into `One` where applicable
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b)
+    }
+
+    /// Removes consecutive elements that resolve to the same key
+    ///
+    /// The list is collapsed into `One` where applicable
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.dedup_by(|a, b| key(a) == key(b))
+    }
+
+    /// Removes consecutive elements for which `same_bucket` returns `true`
+    ///
+    /// The list is collapsed into `One` where applicable
+    pub fn dedup_by<F>(&mut self, same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        if let MaybeList::Many(list) = self {
+            list.dedup_by(same_bucket);
+            self.normalize();
+        }
+    }
+
+    /// Sorts this list, preserving the order of equal elements
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.as_mut_slice().sort()
+    }
+
+    /// Sorts this list with a comparator function, preserving the order of equal elements
+    pub fn sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> core::cmp::Ordering,
+    {
+        self.as_mut_slice().sort_by(compare)
+    }
+
+    /// Sorts this list with a key extraction function, preserving the order of equal elements
+    pub fn sort_by_key<K, F>(&mut self, key: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.as_mut_slice().sort_by_key(key)
+    }
+
+    /// Sorts this list without preserving the order of equal elements
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.as_mut_slice().sort_unstable()
+    }
+
+    /// Reverses the order of the elements in place
+    pub fn reverse(&mut self) {
+        self.as_mut_slice().reverse()
+    }
+
+    // replaces this list with an empty one, returning the old list
+    fn take(&mut self) -> Self {
+        core::mem::replace(self, MaybeList::Empty)
+    }
+
+    // turns an `Empty` or `One` into a `Many` with room for `additional` more elements
+    fn promote(&mut self, additional: usize) -> &mut Vec<T> {
+        if !matches!(self, MaybeList::Many(..)) {
+            let mut list = Vec::with_capacity(self.len().saturating_add(additional));
+            list.extend(self.take());
+            *self = MaybeList::Many(list);
+        }
+
+        match self {
+            MaybeList::Many(list) => list,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Maps each element of this list, keeping its shape
+    pub fn map<U, F>(self, mut f: F) -> MaybeList<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        match self {
+            MaybeList::Empty => MaybeList::Empty,
+            MaybeList::One(item) => MaybeList::One(f(item)),
+            MaybeList::Many(list) => MaybeList::Many(list.into_iter().map(f).collect()),
+        }
+    }
+
+    /// Maps a reference to each element of this list, keeping its shape
+    pub fn map_ref<U, F>(&self, mut f: F) -> MaybeList<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        match self {
+            MaybeList::Empty => MaybeList::Empty,
+            MaybeList::One(item) => MaybeList::One(f(item)),
+            MaybeList::Many(list) => MaybeList::Many(list.iter().map(f).collect()),
+        }
+    }
+
+    /// Maps each element of this list with a fallible function, keeping its shape
+    ///
+    /// This stops at the first error
+    pub fn try_map<U, E, F>(self, mut f: F) -> Result<MaybeList<U>, E>
+    where
+        F: FnMut(T) -> Result<U, E>,
+    {
+        Ok(match self {
+            MaybeList::Empty => MaybeList::Empty,
+            MaybeList::One(item) => MaybeList::One(f(item)?),
+            MaybeList::Many(list) => {
+                MaybeList::Many(list.into_iter().map(f).collect::<Result<_, _>>()?)
+            }
+        })
+    }
+
+    /// Filters and maps each element of this list
+    ///
+    /// The result is collapsed into `Empty` or `One` if fewer than two elements remain
+    pub fn filter_map<U, F>(self, mut f: F) -> MaybeList<U>
+    where
+        F: FnMut(T) -> Option<U>,
+    {
+        match self {
+            MaybeList::Empty => MaybeList::Empty,
+            MaybeList::One(item) => f(item).into(),
+            MaybeList::Many(list) => list.into_iter().filter_map(f).collect(),
+        }
+    }
+
+    /// Maps each element of this list to an iterator and flattens the result
+    ///
+    /// The result is collapsed into `Empty` or `One` if fewer than two elements are produced
+    pub fn flat_map<I, F>(self, f: F) -> MaybeList<I::Item>
+    where
+        I: IntoIterator,
+        F: FnMut(T) -> I,
+    {
+        self.into_iter().flat_map(f).collect()
+    }
+
+    /// Maps each element of this list to a MaybeList and flattens the result
+    ///
+    /// For a `One` list, the result of `f` is returned as-is
+    pub fn and_then<U, F>(self, mut f: F) -> MaybeList<U>
+    where
+        F: FnMut(T) -> MaybeList<U>,
+    {
+        match self {
+            MaybeList::Empty => MaybeList::Empty,
+            MaybeList::One(item) => f(item),
+            MaybeList::Many(list) => list.into_iter().flat_map(f).collect(),
+        }
+    }
+
+    /// Views this list as a slice
+    pub fn as_slice(&self) -> &[T] {
+        match self {
+            MaybeList::Empty => &[],
+            MaybeList::One(item) => core::slice::from_ref(item),
+            MaybeList::Many(list) => list.as_slice(),
+        }
+    }
+
+    /// Views this list as a mutable slice
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        match self {
+            MaybeList::Empty => &mut [],
+            MaybeList::One(item) => core::slice::from_mut(item),
+            MaybeList::Many(list) => list.as_mut_slice(),
+        }
+    }
+
+    /// Returns a reference to an element or subslice, or `None` if the index is out of bounds
+    pub fn get<I>(&self, index: I) -> Option<&I::Output>
+    where
+        I: core::slice::SliceIndex<[T]>,
+    {
+        self.as_slice().get(index)
+    }
+
+    /// Returns a mutable reference to an element or subslice, or `None` if the index is out of bounds
+    pub fn get_mut<I>(&mut self, index: I) -> Option<&mut I::Output>
+    where
+        I: core::slice::SliceIndex<[T]>,
+    {
+        self.as_mut_slice().get_mut(index)
+    }
+
+    /// Returns mutable references to many elements at once, or `None` if any index is out of bounds or repeated
+    pub fn get_many_mut<const N: usize>(&mut self, indices: [usize; N]) -> Option<[&mut T; N]> {
+        self.as_mut_slice().get_disjoint_mut(indices).ok()
+    }
+
+    /// Swaps the elements at `a` and `b`
+    ///
+    /// # Panics
+    /// Panics if `a` or `b` are out of bounds
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.as_mut_slice().swap(a, b)
+    }
+
+    /// Returns an iterator over references to the elements
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            inner: self.as_slice().iter(),
+        }
+    }
+
+    /// Returns an iterator over mutable references to the elements
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            inner: self.as_mut_slice().iter_mut(),
+        }
+    }
 }
 
-impl<T> std::iter::FromIterator<T> for MaybeList<T> {
+impl<T> core::iter::FromIterator<T> for MaybeList<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        MaybeList::Many(iter.into_iter().collect())
+        let mut iter = iter.into_iter();
+        let first = match iter.next() {
+            Some(first) => first,
+            None => return MaybeList::Empty,
+        };
+        let second = match iter.next() {
+            Some(second) => second,
+            None => return MaybeList::One(first),
+        };
+
+        let (lower, _) = iter.size_hint();
+        let mut list = Vec::with_capacity(lower.saturating_add(2));
+        list.push(first);
+        list.push(second);
+        list.extend(iter);
+        MaybeList::Many(list)
     }
 }
 
-impl<T: std::fmt::Debug> std::fmt::Debug for MaybeList<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<T: core::fmt::Debug> core::fmt::Debug for MaybeList<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let mut s = f.debug_struct("MaybeList");
         match self {
+            MaybeList::Empty => &mut s,
             MaybeList::One(item) => s.field("one", &item),
             MaybeList::Many(list) => s.field("many", &list),
         }
@@ -87,6 +912,127 @@ impl<T: std::fmt::Debug> std::fmt::Debug for MaybeList<T> {
     }
 }
 
+impl<T, U> PartialEq<MaybeList<U>> for MaybeList<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &MaybeList<U>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T, U> PartialEq<[U]> for MaybeList<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[U]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T, U> PartialEq<&[U]> for MaybeList<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &&[U]) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl<T, U, const N: usize> PartialEq<[U; N]> for MaybeList<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[U; N]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T, U> PartialEq<Vec<U>> for MaybeList<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &Vec<U>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq> Eq for MaybeList<T> {}
+
+impl<T: PartialOrd> PartialOrd for MaybeList<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Ord> Ord for MaybeList<T> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T: core::hash::Hash> core::hash::Hash for MaybeList<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<T> core::ops::Deref for MaybeList<T> {
+    type Target = [T];
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T> core::ops::DerefMut for MaybeList<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, I> core::ops::Index<I> for MaybeList<T>
+where
+    I: core::slice::SliceIndex<[T]>,
+{
+    type Output = I::Output;
+    fn index(&self, index: I) -> &Self::Output {
+        core::ops::Index::index(self.as_slice(), index)
+    }
+}
+
+impl<T, I> core::ops::IndexMut<I> for MaybeList<T>
+where
+    I: core::slice::SliceIndex<[T]>,
+{
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        core::ops::IndexMut::index_mut(self.as_mut_slice(), index)
+    }
+}
+
+impl<T> AsRef<[T]> for MaybeList<T> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> AsMut<[T]> for MaybeList<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T> core::borrow::Borrow<[T]> for MaybeList<T> {
+    fn borrow(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> core::borrow::BorrowMut<[T]> for MaybeList<T> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
 impl<T> From<T> for MaybeList<T> {
     fn from(d: T) -> Self {
         MaybeList::One(d)
@@ -94,22 +1040,172 @@ impl<T> From<T> for MaybeList<T> {
 }
 
 impl<T> From<Vec<T>> for MaybeList<T> {
+    /// Converts a vec, collapsing it into `Empty` or `One` if it has fewer than two elements
     fn from(d: Vec<T>) -> Self {
-        MaybeList::Many(d)
+        let mut list = MaybeList::Many(d);
+        list.normalize();
+        list
+    }
+}
+
+impl<T> From<Option<T>> for MaybeList<T> {
+    fn from(d: Option<T>) -> Self {
+        match d {
+            Some(item) => MaybeList::One(item),
+            None => MaybeList::Empty,
+        }
     }
 }
 
+impl<T> core::convert::TryFrom<MaybeList<T>> for Option<T> {
+    type Error = TryFromMaybeListError<T>;
+
+    /// Converts a list of zero or one elements into an `Option`, handing the list back if it has more
+    fn try_from(list: MaybeList<T>) -> Result<Self, Self::Error> {
+        match list.len() {
+            0 | 1 => Ok(list.into_iter().next()),
+            _ => Err(TryFromMaybeListError::new(list, Expected::AtMostOne)),
+        }
+    }
+}
+
+impl<T, const N: usize> core::convert::TryFrom<MaybeList<T>> for [T; N] {
+    type Error = TryFromMaybeListError<T>;
+
+    /// Converts a list of exactly `N` elements into an array, handing the list back otherwise
+    fn try_from(list: MaybeList<T>) -> Result<Self, Self::Error> {
+        if list.len() != N {
+            return Err(TryFromMaybeListError::new(list, Expected::Exactly(N)));
+        }
+
+        let mut iter = list.into_iter();
+        Ok(core::array::from_fn(|_| iter.next().unwrap()))
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for MaybeList<T> {
+    fn from(d: [T; N]) -> Self {
+        IntoIterator::into_iter(d).collect()
+    }
+}
+
+impl<T: Clone> From<&[T]> for MaybeList<T> {
+    fn from(d: &[T]) -> Self {
+        d.iter().cloned().collect()
+    }
+}
+
+impl<T> From<alloc::boxed::Box<[T]>> for MaybeList<T> {
+    fn from(d: alloc::boxed::Box<[T]>) -> Self {
+        let mut list = MaybeList::Many(d.into_vec());
+        list.normalize();
+        list
+    }
+}
+
+impl<T> From<alloc::collections::VecDeque<T>> for MaybeList<T> {
+    fn from(d: alloc::collections::VecDeque<T>) -> Self {
+        let mut list = MaybeList::Many(d.into());
+        list.normalize();
+        list
+    }
+}
+
+impl<T> From<MaybeList<T>> for Vec<T> {
+    fn from(d: MaybeList<T>) -> Self {
+        match d {
+            MaybeList::Empty => Vec::new(),
+            MaybeList::One(item) => alloc::vec![item],
+            MaybeList::Many(list) => list,
+        }
+    }
+}
+
+impl<T> From<MaybeList<T>> for alloc::boxed::Box<[T]> {
+    fn from(d: MaybeList<T>) -> Self {
+        Vec::from(d).into_boxed_slice()
+    }
+}
+
+impl<T> From<MaybeList<T>> for alloc::collections::VecDeque<T> {
+    fn from(d: MaybeList<T>) -> Self {
+        Vec::from(d).into()
+    }
+}
+
+impl<T> Extend<T> for MaybeList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter();
+        while !matches!(self, MaybeList::Many(..)) {
+            match iter.next() {
+                Some(item) => self.push(item),
+                None => return,
+            }
+        }
+
+        if let MaybeList::Many(list) = self {
+            list.extend(iter)
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for MaybeList<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+impl<T> Extend<MaybeList<T>> for MaybeList<T> {
+    fn extend<I: IntoIterator<Item = MaybeList<T>>>(&mut self, iter: I) {
+        for list in iter {
+            self.extend(list)
+        }
+    }
+}
+
+// resolves `range` against a list of `len` elements, panicking like slice indexing would
+fn resolve_range<R>(range: R, len: usize) -> core::ops::Range<usize>
+where
+    R: core::ops::RangeBounds<usize>,
+{
+    use core::ops::Bound;
+
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start
+            .checked_add(1)
+            .expect("attempted to index slice from after maximum usize"),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end
+            .checked_add(1)
+            .expect("attempted to index slice up to maximum usize"),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    if start > end {
+        panic!("slice index starts at {} but ends at {}", start, end)
+    }
+    if end > len {
+        panic!(
+            "range end index {} out of range for slice of length {}",
+            end, len
+        )
+    }
+    start..end
+}
+
 impl<T> IntoIterator for MaybeList<T> {
     type Item = T;
     type IntoIter = MaybeListIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
         let item = match self {
-            MaybeList::Many(mut list) => PartialMaybeList::Many({
-                list.reverse();
-                list
-            }),
+            MaybeList::Many(list) => PartialMaybeList::Many(list.into_iter()),
             MaybeList::One(item) => PartialMaybeList::One(Some(item)),
+            MaybeList::Empty => PartialMaybeList::One(None),
         };
 
         Self::IntoIter { item }
@@ -117,7 +1213,7 @@ impl<T> IntoIterator for MaybeList<T> {
 }
 
 enum PartialMaybeList<T> {
-    Many(Vec<T>),
+    Many(alloc::vec::IntoIter<T>),
     One(Option<T>),
 }
 
@@ -129,6 +1225,13 @@ impl<T> PartialMaybeList<T> {
             _ => 0,
         }
     }
+
+    fn as_slice(&self) -> &[T] {
+        match self {
+            PartialMaybeList::Many(list) => list.as_slice(),
+            PartialMaybeList::One(item) => item.as_slice(),
+        }
+    }
 }
 
 /// An iterator over a MaybeList
@@ -136,20 +1239,621 @@ pub struct MaybeListIter<T> {
     item: PartialMaybeList<T>,
 }
 
+impl<T> MaybeListIter<T> {
+    /// Returns the remaining items of this iterator as a slice
+    pub fn as_slice(&self) -> &[T] {
+        self.item.as_slice()
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for MaybeListIter<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("MaybeListIter")
+            .field(&self.as_slice())
+            .finish()
+    }
+}
+
 impl<T> Iterator for MaybeListIter<T> {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
         match self.item {
-            PartialMaybeList::Many(ref mut list) => list.pop(),
+            PartialMaybeList::Many(ref mut list) => list.next(),
+            PartialMaybeList::One(ref mut item) => item.take(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.item.len();
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match self.item {
+            PartialMaybeList::Many(ref mut list) => list.nth(n),
+            PartialMaybeList::One(ref mut item) => item.take().filter(|_| n == 0),
+        }
+    }
+
+    fn count(self) -> usize {
+        self.item.len()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<T> core::iter::DoubleEndedIterator for MaybeListIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.item {
+            PartialMaybeList::Many(ref mut list) => list.next_back(),
             PartialMaybeList::One(ref mut item) => item.take(),
         }
     }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        match self.item {
+            PartialMaybeList::Many(ref mut list) => list.nth_back(n),
+            PartialMaybeList::One(ref mut item) => item.take().filter(|_| n == 0),
+        }
+    }
 }
 
-impl<T> std::iter::FusedIterator for MaybeListIter<T> {}
+impl<T> core::iter::FusedIterator for MaybeListIter<T> {}
 
-impl<T> std::iter::ExactSizeIterator for MaybeListIter<T> {
+impl<T> core::iter::ExactSizeIterator for MaybeListIter<T> {
     fn len(&self) -> usize {
         self.item.len()
     }
 }
+
+impl<'a, T> IntoIterator for &'a MaybeList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut MaybeList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// A draining iterator over elements removed from a MaybeList
+///
+/// This is created by [`MaybeList::drain`]
+pub struct Drain<'a, T> {
+    inner: DrainRepr<'a, T>,
+}
+
+enum DrainRepr<'a, T> {
+    Vec(alloc::vec::Drain<'a, T>),
+    Owned(MaybeListIter<T>),
+}
+
+impl<'a, T> Drain<'a, T> {
+    /// Returns the remaining items of this iterator as a slice
+    pub fn as_slice(&self) -> &[T] {
+        match &self.inner {
+            DrainRepr::Vec(drain) => drain.as_slice(),
+            DrainRepr::Owned(iter) => iter.as_slice(),
+        }
+    }
+}
+
+impl<'a, T: core::fmt::Debug> core::fmt::Debug for Drain<'a, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Drain").field(&self.as_slice()).finish()
+    }
+}
+
+impl<'a, T> Iterator for Drain<'a, T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            DrainRepr::Vec(drain) => drain.next(),
+            DrainRepr::Owned(iter) => iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            DrainRepr::Vec(drain) => drain.size_hint(),
+            DrainRepr::Owned(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl<'a, T> core::iter::DoubleEndedIterator for Drain<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            DrainRepr::Vec(drain) => drain.next_back(),
+            DrainRepr::Owned(iter) => iter.next_back(),
+        }
+    }
+}
+
+impl<'a, T> core::iter::FusedIterator for Drain<'a, T> {}
+
+impl<'a, T> core::iter::ExactSizeIterator for Drain<'a, T> {}
+
+/// An iterator over references to the elements of a MaybeList
+pub struct Iter<'a, T> {
+    inner: core::slice::Iter<'a, T>,
+}
+
+impl<'a, T> Clone for Iter<'a, T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<'a, T: core::fmt::Debug> core::fmt::Debug for Iter<'a, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Iter").field(&self.inner.as_slice()).finish()
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T> core::iter::DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<'a, T> core::iter::FusedIterator for Iter<'a, T> {}
+
+impl<'a, T> core::iter::ExactSizeIterator for Iter<'a, T> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+/// An iterator over mutable references to the elements of a MaybeList
+pub struct IterMut<'a, T> {
+    inner: core::slice::IterMut<'a, T>,
+}
+
+impl<'a, T: core::fmt::Debug> core::fmt::Debug for IterMut<'a, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("IterMut").field(&self.inner).finish()
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T> core::iter::DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<'a, T> core::iter::FusedIterator for IterMut<'a, T> {}
+
+impl<'a, T> core::iter::ExactSizeIterator for IterMut<'a, T> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn push_promotes_and_pop_demotes() {
+        let mut list = MaybeList::Empty;
+        list.push(1);
+        assert!(matches!(list, MaybeList::One(1)));
+        list.push(2);
+        assert!(matches!(list, MaybeList::Many(ref list) if list == &[1, 2]));
+
+        assert_eq!(list.pop(), Some(2));
+        assert!(matches!(list, MaybeList::One(1)));
+        assert_eq!(list.pop(), Some(1));
+        assert!(matches!(list, MaybeList::Empty));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn insert_promotes() {
+        let mut list = MaybeList::Empty;
+        list.insert(0, 2);
+        assert!(matches!(list, MaybeList::One(2)));
+        list.insert(0, 1);
+        assert!(matches!(list, MaybeList::Many(ref list) if list == &[1, 2]));
+        list.insert(2, 3);
+        assert_eq!(list, [1, 2, 3]);
+    }
+
+    #[test]
+    fn remove_and_swap_remove_demote() {
+        let mut list = MaybeList::many(vec![1, 2, 3]);
+        assert_eq!(list.remove(0), 1);
+        assert!(matches!(list, MaybeList::Many(ref list) if list == &[2, 3]));
+        assert_eq!(list.swap_remove(0), 2);
+        assert!(matches!(list, MaybeList::One(3)));
+        assert_eq!(list.remove(0), 3);
+        assert!(matches!(list, MaybeList::Empty));
+
+        let mut list = MaybeList::one(1);
+        assert_eq!(list.swap_remove(0), 1);
+        assert!(matches!(list, MaybeList::Empty));
+    }
+
+    #[test]
+    fn truncate_and_clear_collapse() {
+        let mut list = MaybeList::many(vec![1, 2, 3]);
+        list.truncate(5);
+        assert_eq!(list, [1, 2, 3]);
+        list.truncate(1);
+        assert!(matches!(list, MaybeList::One(1)));
+        list.truncate(0);
+        assert!(matches!(list, MaybeList::Empty));
+
+        let mut list = MaybeList::many(vec![1, 2, 3]);
+        list.clear();
+        assert!(matches!(list, MaybeList::Empty));
+        list.push(4);
+        assert!(matches!(list, MaybeList::One(4)));
+        list.clear();
+        assert!(matches!(list, MaybeList::Empty));
+    }
+
+    #[test]
+    #[should_panic(expected = "insertion index (is 1) should be <= len (is 0)")]
+    fn insert_out_of_bounds_on_empty() {
+        MaybeList::Empty.insert(1, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "insertion index (is 2) should be <= len (is 1)")]
+    fn insert_out_of_bounds_on_one() {
+        MaybeList::one(1).insert(2, 2);
+    }
+
+    #[test]
+    fn insert_out_of_bounds_on_one_keeps_the_list() {
+        let mut list = MaybeList::one(1);
+        let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| list.insert(2, 2)));
+        assert!(result.is_err());
+        assert!(matches!(list, MaybeList::One(1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "removal index (is 0) should be < len (is 0)")]
+    fn remove_out_of_bounds_on_empty() {
+        MaybeList::<i32>::Empty.remove(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "removal index (is 1) should be < len (is 1)")]
+    fn remove_out_of_bounds_on_one() {
+        MaybeList::one(1).remove(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "swap_remove index (is 0) should be < len (is 0)")]
+    fn swap_remove_out_of_bounds_on_empty() {
+        MaybeList::<i32>::Empty.swap_remove(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "swap_remove index (is 1) should be < len (is 1)")]
+    fn swap_remove_out_of_bounds_on_one() {
+        MaybeList::one(1).swap_remove(1);
+    }
+
+    #[test]
+    fn panic_messages_match_vec() {
+        fn message(f: impl FnOnce() + std::panic::UnwindSafe) -> std::string::String {
+            let payload = std::panic::catch_unwind(f).unwrap_err();
+            match payload.downcast::<std::string::String>() {
+                Ok(message) => *message,
+                Err(payload) => (*payload.downcast::<&str>().unwrap()).into(),
+            }
+        }
+
+        assert_eq!(
+            message(|| MaybeList::one(1).insert(2, 0)),
+            message(|| vec![1].insert(2, 0)),
+        );
+        assert_eq!(
+            message(|| {
+                MaybeList::one(1).remove(1);
+            }),
+            message(|| {
+                vec![1].remove(1);
+            }),
+        );
+        assert_eq!(
+            message(|| {
+                MaybeList::one(1).swap_remove(1);
+            }),
+            message(|| {
+                vec![1].swap_remove(1);
+            }),
+        );
+    }
+
+    #[test]
+    fn into_iter_nth_on_one() {
+        let mut iter = MaybeList::one(1).into_iter();
+        assert_eq!(iter.nth(1), None);
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+
+        let mut iter = MaybeList::one(1).into_iter();
+        assert_eq!(iter.nth_back(0), Some(1));
+        assert_eq!(iter.next(), None);
+
+        let mut iter = MaybeList::<i32>::Empty.into_iter();
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.nth(2), None);
+    }
+
+    #[test]
+    fn into_iter_nth_back_on_many() {
+        let mut iter = MaybeList::many(vec![1, 2, 3, 4, 5]).into_iter();
+        assert_eq!(iter.nth_back(1), Some(4));
+        assert_eq!(iter.as_slice(), &[1, 2, 3]);
+        assert_eq!(iter.nth(1), Some(2));
+        assert_eq!(iter.as_slice(), &[3]);
+        assert_eq!(iter.nth_back(1), None);
+        assert_eq!(iter.len(), 0);
+    }
+
+    #[test]
+    fn into_iter_len_after_partial_iteration() {
+        let mut iter = MaybeList::many(vec![1, 2, 3, 4]).into_iter();
+        assert_eq!(iter.size_hint(), (4, Some(4)));
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(iter.rev().collect::<Vec<_>>(), [3, 2]);
+
+        let mut iter = MaybeList::one(1).into_iter();
+        assert_eq!(iter.len(), 1);
+        iter.next();
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn one_equals_and_hashes_like_many() {
+        use std::collections::HashSet;
+
+        let one = MaybeList::One(1);
+        let many = MaybeList::Many(vec![1]);
+        assert_eq!(one, many);
+        assert_eq!(one.cmp(&many), core::cmp::Ordering::Equal);
+        assert!(MaybeList::One(2) > MaybeList::Many(vec![1, 5]));
+        assert!(MaybeList::Empty < MaybeList::One(0));
+        assert_eq!(MaybeList::<i32>::Empty, MaybeList::<i32>::Many(vec![]));
+
+        let mut set = HashSet::new();
+        assert!(set.insert(one));
+        assert!(!set.insert(many));
+        assert!(set.insert(MaybeList::Empty));
+        assert!(!set.insert(MaybeList::Many(vec![])));
+        assert!(set.insert(MaybeList::Many(vec![1, 2])));
+        assert_eq!(set.len(), 3);
+    }
+
+    #[test]
+    fn partial_eq_across_types() {
+        let one = MaybeList::one(1);
+        let many = MaybeList::many(vec![1, 2]);
+
+        assert_eq!(one, *[1].as_slice());
+        assert_eq!(one, &[1][..]);
+        assert_eq!(one, [1]);
+        assert_eq!(one, vec![1]);
+        assert_ne!(one, [1, 2]);
+
+        assert_eq!(many, *[1, 2].as_slice());
+        assert_eq!(many, &[1, 2][..]);
+        assert_eq!(many, [1, 2]);
+        assert_eq!(many, vec![1, 2]);
+        assert_ne!(many, vec![1]);
+
+        assert_eq!(MaybeList::<i32>::Empty, [0; 0]);
+        assert_eq!(MaybeList::<i32>::Empty, Vec::<i32>::new());
+    }
+
+    #[test]
+    fn append_shapes() {
+        let mut list = MaybeList::Empty;
+        list.append(&mut MaybeList::Empty);
+        assert!(matches!(list, MaybeList::Empty));
+
+        let mut other = MaybeList::one(1);
+        list.append(&mut other);
+        assert!(matches!(list, MaybeList::One(1)));
+        assert!(matches!(other, MaybeList::Empty));
+
+        list.append(&mut MaybeList::one(2));
+        assert!(matches!(list, MaybeList::Many(ref list) if list == &[1, 2]));
+
+        let mut other = MaybeList::many(vec![3, 4]);
+        list.append(&mut other);
+        assert_eq!(list, [1, 2, 3, 4]);
+        assert!(matches!(other, MaybeList::Empty));
+
+        let mut list = MaybeList::one(0);
+        list.append(&mut MaybeList::many(vec![1, 2]));
+        assert_eq!(list, [0, 1, 2]);
+    }
+
+    #[test]
+    fn append_into_empty_reuses_the_allocation() {
+        let mut other = MaybeList::many(vec![1, 2, 3]);
+        let ptr = other.as_slice().as_ptr();
+
+        let mut list = MaybeList::Empty;
+        list.append(&mut other);
+        assert_eq!(list.as_slice().as_ptr(), ptr);
+        assert!(matches!(other, MaybeList::Empty));
+    }
+
+    #[test]
+    fn drain_keeps_many_when_two_or_more_remain() {
+        let mut list = MaybeList::many(vec![1, 2, 3, 4]);
+        let mut drain = list.drain(1..3);
+        assert_eq!(drain.as_slice(), &[2, 3]);
+        assert_eq!(drain.next_back(), Some(3));
+        drop(drain);
+        assert!(matches!(list, MaybeList::Many(ref list) if list == &[1, 4]));
+    }
+
+    #[test]
+    fn drain_collapses_when_at_most_one_remains() {
+        // the leftover element is at the back
+        let mut list = MaybeList::many(vec![1, 2, 3]);
+        assert_eq!(list.drain(..2).collect::<Vec<_>>(), [1, 2]);
+        assert!(matches!(list, MaybeList::One(3)));
+
+        // the leftover element is at the front
+        let mut list = MaybeList::many(vec![1, 2, 3]);
+        assert_eq!(list.drain(1..).collect::<Vec<_>>(), [2, 3]);
+        assert!(matches!(list, MaybeList::One(1)));
+
+        // nothing is left over
+        let mut list = MaybeList::many(vec![1, 2, 3]);
+        let drain = list.drain(..);
+        assert_eq!(drain.len(), 3);
+        drop(drain);
+        assert!(matches!(list, MaybeList::Empty));
+    }
+
+    #[test]
+    fn drain_one_and_empty() {
+        let mut list = MaybeList::one(1);
+        assert_eq!(list.drain(1..).len(), 0);
+        assert!(matches!(list, MaybeList::One(1)));
+        assert_eq!(list.drain(0..0).len(), 0);
+        assert!(matches!(list, MaybeList::One(1)));
+        assert_eq!(list.drain(..=0).collect::<Vec<_>>(), [1]);
+        assert!(matches!(list, MaybeList::Empty));
+
+        assert_eq!(list.drain(..).next(), None);
+        assert!(matches!(list, MaybeList::Empty));
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 2 out of range for slice of length 1")]
+    fn drain_out_of_bounds_on_one() {
+        MaybeList::one(1).drain(..2);
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 1 out of range for slice of length 0")]
+    fn drain_out_of_bounds_on_empty() {
+        MaybeList::<i32>::Empty.drain(..1);
+    }
+
+    #[test]
+    #[should_panic(expected = "slice index starts at 2 but ends at 1")]
+    fn drain_inverted_range() {
+        #[allow(clippy::reversed_empty_ranges)]
+        MaybeList::many(vec![1, 2, 3]).drain(2..1);
+    }
+
+    #[test]
+    fn conversions_collapse_single_elements() {
+        assert!(matches!(
+            MaybeList::<i32>::from(Vec::new()),
+            MaybeList::Empty
+        ));
+        assert!(matches!(MaybeList::<i32>::from(vec![1]), MaybeList::One(1)));
+        assert!(matches!(
+            MaybeList::<i32>::from(vec![1, 2]),
+            MaybeList::Many(..)
+        ));
+
+        let boxed: alloc::boxed::Box<[i32]> = alloc::boxed::Box::new([1]);
+        assert!(matches!(MaybeList::<i32>::from(boxed), MaybeList::One(1)));
+        let deque: alloc::collections::VecDeque<i32> = [1].into();
+        assert!(matches!(MaybeList::<i32>::from(deque), MaybeList::One(1)));
+        assert!(matches!(MaybeList::<i32>::from([1]), MaybeList::One(1)));
+        assert!(matches!(
+            MaybeList::<i32>::from(&[1][..]),
+            MaybeList::One(1)
+        ));
+        assert!(matches!(
+            MaybeList::<i32>::from([0; 0]),
+            MaybeList::<i32>::Empty
+        ));
+    }
+
+    #[test]
+    fn retain_mut_collapses() {
+        let mut list = MaybeList::many(vec![1, 2, 3]);
+        list.retain_mut(|x| {
+            *x *= 10;
+            *x > 10
+        });
+        assert!(matches!(list, MaybeList::Many(ref list) if list == &[20, 30]));
+        list.retain_mut(|x| *x == 30);
+        assert!(matches!(list, MaybeList::One(30)));
+        list.retain_mut(|x| {
+            *x += 1;
+            true
+        });
+        assert!(matches!(list, MaybeList::One(31)));
+        list.retain_mut(|_| false);
+        assert!(matches!(list, MaybeList::Empty));
+    }
+
+    #[test]
+    fn dedup_collapses() {
+        let mut list = MaybeList::many(vec![1, 1, 2, 2, 1]);
+        list.dedup();
+        assert!(matches!(list, MaybeList::Many(ref list) if list == &[1, 2, 1]));
+
+        let mut list = MaybeList::many(vec![1, 1, 1]);
+        list.dedup();
+        assert!(matches!(list, MaybeList::One(1)));
+
+        let mut list = MaybeList::many(vec![1, 3, 5]);
+        list.dedup_by_key(|x| *x % 2);
+        assert!(matches!(list, MaybeList::One(1)));
+
+        let mut list = MaybeList::many(vec![1, 2, 3]);
+        list.dedup_by(|a, b| a > b);
+        assert!(matches!(list, MaybeList::One(1)));
+
+        let mut list = MaybeList::one(1);
+        list.dedup();
+        assert!(matches!(list, MaybeList::One(1)));
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{Iter, MaybeList};
+
+/// A borrowed MaybeList that refers to either no elements, 1 element, or many elements
+#[derive(Default)]
+pub enum MaybeListRef<'a, T> {
+    /// No elements
+    #[default]
+    Empty,
+    /// A single borrowed element
+    One(&'a T),
+    /// Multiple borrowed elements
+    Many(&'a [T]),
+}
+
+impl<'a, T> MaybeListRef<'a, T> {
+    /// Returns the length of this list
+    pub fn len(&self) -> usize {
+        match self {
+            MaybeListRef::Empty => 0,
+            MaybeListRef::One(..) => 1,
+            MaybeListRef::Many(list) => list.len(),
+        }
+    }
+
+    /// Returns whether this list is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Views this list as a slice
+    pub fn as_slice(&self) -> &'a [T] {
+        match *self {
+            MaybeListRef::Empty => &[],
+            MaybeListRef::One(item) => core::slice::from_ref(item),
+            MaybeListRef::Many(list) => list,
+        }
+    }
+
+    /// Returns an iterator over references to the elements
+    pub fn iter(&self) -> Iter<'a, T> {
+        Iter {
+            inner: self.as_slice().iter(),
+        }
+    }
+
+    /// Clones the borrowed elements into an owned MaybeList, keeping its shape
+    pub fn to_maybe_list(&self) -> MaybeList<T>
+    where
+        T: Clone,
+    {
+        match *self {
+            MaybeListRef::Empty => MaybeList::Empty,
+            MaybeListRef::One(item) => MaybeList::One(item.clone()),
+            MaybeListRef::Many(list) => MaybeList::Many(list.to_vec()),
+        }
+    }
+}
+
+impl<T> MaybeList<T> {
+    /// Borrows this list as a MaybeListRef, keeping its shape
+    pub fn as_list_ref(&self) -> MaybeListRef<'_, T> {
+        match self {
+            MaybeList::Empty => MaybeListRef::Empty,
+            MaybeList::One(item) => MaybeListRef::One(item),
+            MaybeList::Many(list) => MaybeListRef::Many(list),
+        }
+    }
+}
+
+impl<'a, T> Clone for MaybeListRef<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for MaybeListRef<'a, T> {}
+
+impl<'a, T: core::fmt::Debug> core::fmt::Debug for MaybeListRef<'a, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut s = f.debug_struct("MaybeListRef");
+        match self {
+            MaybeListRef::Empty => &mut s,
+            MaybeListRef::One(item) => s.field("one", item),
+            MaybeListRef::Many(list) => s.field("many", list),
+        }
+        .finish()
+    }
+}
+
+impl<'a, 'b, T, U> PartialEq<MaybeListRef<'b, U>> for MaybeListRef<'a, T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &MaybeListRef<'b, U>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<'a, T: Eq> Eq for MaybeListRef<'a, T> {}
+
+impl<'a, T: core::hash::Hash> core::hash::Hash for MaybeListRef<'a, T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<'a, T> core::ops::Deref for MaybeListRef<'a, T> {
+    type Target = [T];
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<'a, T> From<&'a T> for MaybeListRef<'a, T> {
+    fn from(d: &'a T) -> Self {
+        MaybeListRef::One(d)
+    }
+}
+
+impl<'a, T> From<&'a [T]> for MaybeListRef<'a, T> {
+    fn from(d: &'a [T]) -> Self {
+        match d {
+            [] => MaybeListRef::Empty,
+            [item] => MaybeListRef::One(item),
+            list => MaybeListRef::Many(list),
+        }
+    }
+}
+
+impl<'a, T> From<&'a MaybeList<T>> for MaybeListRef<'a, T> {
+    fn from(d: &'a MaybeList<T>) -> Self {
+        d.as_list_ref()
+    }
+}
+
+impl<'a, T> IntoIterator for MaybeListRef<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
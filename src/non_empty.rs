@@ -0,0 +1,195 @@
+use crate::error::Expected;
+use crate::{Iter, MaybeList, MaybeListIter, TryFromMaybeListError};
+
+/// A MaybeList that is guaranteed to hold at least one element
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NonEmptyMaybeList<T> {
+    list: MaybeList<T>,
+}
+
+impl<T> NonEmptyMaybeList<T> {
+    /// A NonEmptyMaybeList of one element
+    pub fn one(item: T) -> Self {
+        Self {
+            list: MaybeList::One(item),
+        }
+    }
+
+    /// A NonEmptyMaybeList of many elements, or `None` if the iterator yields no elements
+    pub fn many(list: impl IntoIterator<Item = T>) -> Option<Self> {
+        match list.into_iter().collect() {
+            MaybeList::Empty => None,
+            list => Some(Self { list }),
+        }
+    }
+
+    /// Returns the length of this list
+    pub fn len(&self) -> core::num::NonZeroUsize {
+        core::num::NonZeroUsize::new(self.list.len()).expect("list should never be empty")
+    }
+
+    /// Always returns `false`
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns a reference to the first element
+    pub fn first(&self) -> &T {
+        &self.list.as_slice()[0]
+    }
+
+    /// Returns a mutable reference to the first element
+    pub fn first_mut(&mut self) -> &mut T {
+        &mut self.list.as_mut_slice()[0]
+    }
+
+    /// Returns a reference to the last element
+    pub fn last(&self) -> &T {
+        let slice = self.list.as_slice();
+        &slice[slice.len() - 1]
+    }
+
+    /// Returns a mutable reference to the last element
+    pub fn last_mut(&mut self) -> &mut T {
+        let slice = self.list.as_mut_slice();
+        let last = slice.len() - 1;
+        &mut slice[last]
+    }
+
+    /// Returns a reference to the maximum element
+    ///
+    /// If several elements are equally maximum, the last one is returned
+    pub fn max(&self) -> &T
+    where
+        T: Ord,
+    {
+        self.reduce_ref(|left, right| core::cmp::max(left, right))
+    }
+
+    /// Returns a reference to the minimum element
+    ///
+    /// If several elements are equally minimum, the first one is returned
+    pub fn min(&self) -> &T
+    where
+        T: Ord,
+    {
+        self.reduce_ref(|left, right| core::cmp::min(left, right))
+    }
+
+    /// Reduces the elements to a single one by repeatedly applying `f`
+    pub fn reduce<F>(self, f: F) -> T
+    where
+        F: FnMut(T, T) -> T,
+    {
+        self.list
+            .into_iter()
+            .reduce(f)
+            .expect("list should never be empty")
+    }
+
+    fn reduce_ref<'a, F>(&'a self, f: F) -> &'a T
+    where
+        F: FnMut(&'a T, &'a T) -> &'a T,
+    {
+        self.list
+            .iter()
+            .reduce(f)
+            .expect("list should never be empty")
+    }
+
+    /// Appends an element to the back of this list
+    pub fn push(&mut self, item: T) {
+        self.list.push(item)
+    }
+
+    /// Maps each element of this list, keeping its shape
+    pub fn map<U, F>(self, f: F) -> NonEmptyMaybeList<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        NonEmptyMaybeList {
+            list: self.list.map(f),
+        }
+    }
+
+    /// Views this list as a slice
+    pub fn as_slice(&self) -> &[T] {
+        self.list.as_slice()
+    }
+
+    /// Views this list as a mutable slice
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.list.as_mut_slice()
+    }
+
+    /// Returns an iterator over references to the elements
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.list.iter()
+    }
+
+    /// Views this list as a MaybeList
+    pub fn as_list(&self) -> &MaybeList<T> {
+        &self.list
+    }
+
+    /// Returns the underlying MaybeList
+    pub fn into_inner(self) -> MaybeList<T> {
+        self.list
+    }
+}
+
+impl<T> core::ops::Deref for NonEmptyMaybeList<T> {
+    type Target = [T];
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T> core::ops::DerefMut for NonEmptyMaybeList<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T> From<T> for NonEmptyMaybeList<T> {
+    fn from(d: T) -> Self {
+        Self::one(d)
+    }
+}
+
+impl<T> From<NonEmptyMaybeList<T>> for MaybeList<T> {
+    fn from(d: NonEmptyMaybeList<T>) -> Self {
+        d.list
+    }
+}
+
+impl<T> core::convert::TryFrom<MaybeList<T>> for NonEmptyMaybeList<T> {
+    type Error = TryFromMaybeListError<T>;
+
+    /// Converts a list of at least one element, handing the list back if it is empty
+    fn try_from(mut list: MaybeList<T>) -> Result<Self, Self::Error> {
+        list.normalize();
+        match list {
+            MaybeList::Empty => Err(TryFromMaybeListError::new(list, Expected::AtLeastOne)),
+            list => Ok(Self { list }),
+        }
+    }
+}
+
+impl<T> IntoIterator for NonEmptyMaybeList<T> {
+    type Item = T;
+    type IntoIter = MaybeListIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a NonEmptyMaybeList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,113 @@
+use crate::MaybeList;
+use ::rayon::iter::{
+    Either, FromParallelIterator, IntoParallelIterator, IntoParallelRefIterator,
+    IntoParallelRefMutIterator, ParallelExtend, ParallelIterator,
+};
+
+impl<T: Send> IntoParallelIterator for MaybeList<T> {
+    type Item = T;
+    type Iter = Either<::rayon::option::IntoIter<T>, ::rayon::vec::IntoIter<T>>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        match self {
+            MaybeList::Empty => Either::Left(None.into_par_iter()),
+            MaybeList::One(item) => Either::Left(Some(item).into_par_iter()),
+            MaybeList::Many(list) => Either::Right(list.into_par_iter()),
+        }
+    }
+}
+
+impl<'a, T: Sync> IntoParallelIterator for &'a MaybeList<T> {
+    type Item = &'a T;
+    type Iter = ::rayon::slice::Iter<'a, T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.as_slice().par_iter()
+    }
+}
+
+impl<'a, T: Send> IntoParallelIterator for &'a mut MaybeList<T> {
+    type Item = &'a mut T;
+    type Iter = ::rayon::slice::IterMut<'a, T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.as_mut_slice().par_iter_mut()
+    }
+}
+
+impl<T: Send> FromParallelIterator<T> for MaybeList<T> {
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = T>,
+    {
+        let par_iter = par_iter.into_par_iter();
+        match par_iter.opt_len() {
+            // a known length of at least two can be collected straight into a vec
+            Some(len) if len > 1 => MaybeList::Many(par_iter.collect()),
+            // otherwise each chunk is collected into a MaybeList, so a single item never allocates
+            _ => par_iter
+                .fold(MaybeList::default, |mut list, item| {
+                    list.push(item);
+                    list
+                })
+                .reduce(MaybeList::default, |mut left, mut right| {
+                    left.append(&mut right);
+                    left
+                }),
+        }
+    }
+}
+
+impl<T: Send> ParallelExtend<T> for MaybeList<T> {
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = T>,
+    {
+        self.promote(0).par_extend(par_iter);
+        self.normalize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MaybeList;
+    use ::rayon::iter::{IntoParallelIterator, ParallelExtend, ParallelIterator};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn collect_keeps_shape() {
+        let list: MaybeList<i32> = (0..0).into_par_iter().collect();
+        assert!(matches!(list, MaybeList::Empty));
+
+        let list: MaybeList<i32> = (0..1).into_par_iter().collect();
+        assert!(matches!(list, MaybeList::One(0)));
+
+        let list: MaybeList<i32> = (0..1000).into_par_iter().collect();
+        assert!(matches!(list, MaybeList::Many(..)));
+        assert!(list.iter().copied().eq(0..1000));
+    }
+
+    #[test]
+    fn collect_unindexed_keeps_shape_and_order() {
+        let list: MaybeList<i32> = (0..1000).into_par_iter().filter(|&x| x == 500).collect();
+        assert!(matches!(list, MaybeList::One(500)));
+
+        let list: MaybeList<i32> = (0..1000).into_par_iter().filter(|_| false).collect();
+        assert!(matches!(list, MaybeList::Empty));
+
+        let list: MaybeList<i32> = (0..1000).into_par_iter().filter(|x| x % 3 == 0).collect();
+        assert!(matches!(list, MaybeList::Many(..)));
+        assert!(list.iter().copied().eq((0..1000).filter(|x| x % 3 == 0)));
+    }
+
+    #[test]
+    fn par_iter_round_trip() {
+        let list = MaybeList::many((0..100).collect::<Vec<_>>());
+        let doubled: MaybeList<i32> = list.into_par_iter().map(|x| x * 2).collect();
+        assert!(doubled.iter().copied().eq((0..100).map(|x| x * 2)));
+
+        let mut list = MaybeList::Empty;
+        list.par_extend((0..1).into_par_iter());
+        assert!(matches!(list, MaybeList::One(0)));
+    }
+}
@@ -0,0 +1,343 @@
+//! Serde support for [`MaybeList`]
+//!
+//! A single value is represented as a scalar, and no values or many values as a sequence.
+//! Use [`as_seq`] to always serialize as a sequence.
+//!
+//! ```
+//! use maybe_list::MaybeList;
+//!
+//! let one: MaybeList<String> = serde_json::from_str(r#""a""#).unwrap();
+//! assert_eq!(one, MaybeList::one("a".to_string()));
+//! assert_eq!(serde_json::to_string(&one).unwrap(), r#""a""#);
+//!
+//! let many: MaybeList<String> = serde_json::from_str(r#"["a","b"]"#).unwrap();
+//! assert_eq!(many, ["a", "b"]);
+//! assert_eq!(serde_json::to_string(&many).unwrap(), r#"["a","b"]"#);
+//!
+//! let empty: MaybeList<String> = serde_json::from_str("[]").unwrap();
+//! assert!(matches!(empty, MaybeList::Empty));
+//! assert_eq!(serde_json::to_string(&empty).unwrap(), "[]");
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Tags {
+//!     #[serde(with = "maybe_list::serde::as_seq")]
+//!     tags: MaybeList<String>,
+//! }
+//!
+//! let tags = Tags { tags: MaybeList::one("a".to_string()) };
+//! let json = serde_json::to_string(&tags).unwrap();
+//! assert_eq!(json, r#"{"tags":["a"]}"#);
+//! let tags: Tags = serde_json::from_str(&json).unwrap();
+//! assert_eq!(tags.tags, ["a"]);
+//! ```
+use crate::MaybeList;
+use ::serde::de::{self, value, EnumAccess, MapAccess, SeqAccess, Visitor};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use alloc::{string::String, vec::Vec};
+use core::{fmt, marker::PhantomData};
+
+impl<T: Serialize> Serialize for MaybeList<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            MaybeList::One(item) => item.serialize(serializer),
+            _ => self.as_slice().serialize(serializer),
+        }
+    }
+}
+
+struct MaybeListVisitor<T>(PhantomData<T>);
+
+macro_rules! forward_scalar {
+    ($($visit:ident($ty:ty) => $de:ty;)*) => {
+        $(
+            fn $visit<E: de::Error>(self, value: $ty) -> Result<Self::Value, E> {
+                T::deserialize(Hinted(<$de>::new(value))).map(MaybeList::One)
+            }
+        )*
+    };
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for MaybeListVisitor<T> {
+    type Value = MaybeList<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a single value or a sequence")
+    }
+
+    forward_scalar! {
+        visit_bool(bool) => value::BoolDeserializer<E>;
+        visit_i8(i8) => value::I8Deserializer<E>;
+        visit_i16(i16) => value::I16Deserializer<E>;
+        visit_i32(i32) => value::I32Deserializer<E>;
+        visit_i64(i64) => value::I64Deserializer<E>;
+        visit_i128(i128) => value::I128Deserializer<E>;
+        visit_u8(u8) => value::U8Deserializer<E>;
+        visit_u16(u16) => value::U16Deserializer<E>;
+        visit_u32(u32) => value::U32Deserializer<E>;
+        visit_u64(u64) => value::U64Deserializer<E>;
+        visit_u128(u128) => value::U128Deserializer<E>;
+        visit_f32(f32) => value::F32Deserializer<E>;
+        visit_f64(f64) => value::F64Deserializer<E>;
+        visit_char(char) => value::CharDeserializer<E>;
+        visit_str(&str) => value::StrDeserializer<'_, E>;
+        visit_borrowed_str(&'de str) => value::BorrowedStrDeserializer<'de, E>;
+        visit_string(String) => value::StringDeserializer<E>;
+        visit_bytes(&[u8]) => value::BytesDeserializer<'_, E>;
+        visit_borrowed_bytes(&'de [u8]) => value::BorrowedBytesDeserializer<'de, E>;
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, value: Vec<u8>) -> Result<Self::Value, E> {
+        self.visit_bytes(&value)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        T::deserialize(value::UnitDeserializer::new()).map(MaybeList::One)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        T::deserialize(Hinted(value::MapAccessDeserializer::new(map))).map(MaybeList::One)
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        T::deserialize(Hinted(value::EnumAccessDeserializer::new(data))).map(MaybeList::One)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut list = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(item) = seq.next_element()? {
+            list.push(item);
+        }
+        Ok(match list.len() {
+            0 => MaybeList::Empty,
+            _ => MaybeList::Many(list),
+        })
+    }
+}
+
+// a single value that has already been read, so `Option` and newtype structs are
+// treated as wrapping it rather than being rejected by the underlying deserializer
+struct Hinted<D>(D);
+
+macro_rules! forward_hinted {
+    ($($method:ident($($arg:ident: $ty:ty),*);)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, $($arg: $ty,)* visitor: V) -> Result<V::Value, Self::Error> {
+                self.0.$method($($arg,)* visitor)
+            }
+        )*
+    };
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for Hinted<D> {
+    type Error = D::Error;
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    forward_hinted! {
+        deserialize_any();
+        deserialize_bool();
+        deserialize_i8();
+        deserialize_i16();
+        deserialize_i32();
+        deserialize_i64();
+        deserialize_i128();
+        deserialize_u8();
+        deserialize_u16();
+        deserialize_u32();
+        deserialize_u64();
+        deserialize_u128();
+        deserialize_f32();
+        deserialize_f64();
+        deserialize_char();
+        deserialize_str();
+        deserialize_string();
+        deserialize_bytes();
+        deserialize_byte_buf();
+        deserialize_unit();
+        deserialize_unit_struct(name: &'static str);
+        deserialize_seq();
+        deserialize_tuple(len: usize);
+        deserialize_tuple_struct(name: &'static str, len: usize);
+        deserialize_map();
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]);
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]);
+        deserialize_identifier();
+        deserialize_ignored_any();
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for MaybeList<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(MaybeListVisitor(PhantomData))
+    }
+}
+
+/// Always serialize a [`MaybeList`] as a sequence
+///
+/// Deserialization only accepts a sequence, so this also works with formats
+/// that are not self-describing.
+///
+/// Use with `#[serde(with = "maybe_list::serde::as_seq")]`
+pub mod as_seq {
+    use crate::MaybeList;
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use core::marker::PhantomData;
+
+    /// Serializes the list as a sequence
+    pub fn serialize<T, S>(list: &MaybeList<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        serializer.collect_seq(list)
+    }
+
+    /// Deserializes the list from a sequence
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<MaybeList<T>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(super::MaybeListVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MaybeList;
+    use ::serde::{de::DeserializeOwned, Deserialize, Serialize};
+    use alloc::{
+        string::{String, ToString},
+        vec,
+    };
+    use core::fmt::Debug;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Id(String);
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Wrapper(Point);
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Kind {
+        Unit,
+        Newtype(i32),
+    }
+
+    fn from_json<T: DeserializeOwned>(json: &str) -> MaybeList<T> {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[track_caller]
+    fn round_trip<T>(list: MaybeList<T>, json: &str)
+    where
+        T: Serialize + DeserializeOwned + PartialEq + Debug,
+    {
+        assert_eq!(serde_json::to_string(&list).unwrap(), json);
+        let parsed = from_json::<T>(json);
+        assert_eq!(parsed, list);
+        assert_eq!(
+            core::mem::discriminant(&parsed),
+            core::mem::discriminant(&list)
+        );
+    }
+
+    #[test]
+    fn newtype_struct() {
+        round_trip(MaybeList::one(Id("a".into())), r#""a""#);
+        round_trip(
+            MaybeList::many(vec![Id("a".into()), Id("b".into())]),
+            r#"["a","b"]"#,
+        );
+        round_trip(
+            MaybeList::one(Wrapper(Point { x: 1, y: 2 })),
+            r#"{"x":1,"y":2}"#,
+        );
+    }
+
+    #[test]
+    fn option() {
+        round_trip(MaybeList::one(Some(3)), "3");
+        round_trip(MaybeList::<Option<i32>>::one(None), "null");
+        round_trip(MaybeList::many(vec![Some(1), None]), "[1,null]");
+        round_trip(MaybeList::one(Some(Id("a".into()))), r#""a""#);
+        round_trip(
+            MaybeList::one(Some(Point { x: 1, y: 2 })),
+            r#"{"x":1,"y":2}"#,
+        );
+    }
+
+    #[test]
+    fn enums() {
+        round_trip(MaybeList::one(Kind::Unit), r#""Unit""#);
+        round_trip(MaybeList::one(Kind::Newtype(1)), r#"{"Newtype":1}"#);
+        round_trip(
+            MaybeList::many(vec![Kind::Unit, Kind::Newtype(1)]),
+            r#"["Unit",{"Newtype":1}]"#,
+        );
+    }
+
+    #[test]
+    fn structs() {
+        round_trip(MaybeList::one(Point { x: 1, y: 2 }), r#"{"x":1,"y":2}"#);
+        round_trip(
+            MaybeList::many(vec![Point { x: 1, y: 2 }, Point { x: 3, y: 4 }]),
+            r#"[{"x":1,"y":2},{"x":3,"y":4}]"#,
+        );
+    }
+
+    #[test]
+    fn scalars_and_empty() {
+        round_trip(MaybeList::one(true), "true");
+        round_trip(MaybeList::one(-1_i64), "-1");
+        round_trip(MaybeList::one(1.5_f64), "1.5");
+        round_trip(MaybeList::one('c'), r#""c""#);
+        round_trip(MaybeList::one(String::from("a")), r#""a""#);
+        round_trip(MaybeList::<String>::Empty, "[]");
+
+        let borrowed: MaybeList<&str> = serde_json::from_str(r#""a""#).unwrap();
+        assert!(matches!(borrowed, MaybeList::One("a")));
+    }
+
+    #[test]
+    fn element_errors_are_kept() {
+        let error = serde_json::from_str::<MaybeList<u32>>(r#""a""#).unwrap_err();
+        assert!(error.to_string().contains("expected u32"), "{}", error);
+
+        let error = serde_json::from_str::<MaybeList<u32>>(r#"[1,"a"]"#).unwrap_err();
+        assert!(error.to_string().contains("expected u32"), "{}", error);
+    }
+
+    #[test]
+    fn as_seq() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Tags {
+            #[serde(with = "crate::serde::as_seq")]
+            tags: MaybeList<Id>,
+        }
+
+        let tags = Tags {
+            tags: MaybeList::one(Id("a".into())),
+        };
+        let json = serde_json::to_string(&tags).unwrap();
+        assert_eq!(json, r#"{"tags":["a"]}"#);
+        assert_eq!(serde_json::from_str::<Tags>(&json).unwrap(), tags);
+        assert!(serde_json::from_str::<Tags>(r#"{"tags":"a"}"#).is_err());
+    }
+}